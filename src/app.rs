@@ -0,0 +1,85 @@
+//! App state and the single place it's mutated.
+
+use std::time::Instant;
+
+use ratatui::layout::Rect;
+
+use crate::message::{Cue, Message};
+use crate::theme::Theme;
+
+/// Different ASCII rat art styles
+#[derive(Clone, Copy)]
+pub enum RatStyle {
+    Classic,
+    LongTail,
+    Chubby,
+}
+
+/// App state
+#[derive(Clone)]
+pub struct App {
+    pub last_pet: Instant,
+    pub happiness: f32,
+    pub vibe: f32,
+    pub squeaks: usize,
+    pub style: RatStyle,
+    pub should_quit: bool,
+    pub theme: Theme,
+}
+
+impl App {
+    pub fn new(theme: Theme) -> Self {
+        Self {
+            last_pet: Instant::now(),
+            happiness: 0.5,
+            vibe: 0.0,
+            squeaks: 0,
+            style: RatStyle::Classic,
+            should_quit: false,
+            theme,
+        }
+    }
+
+    /// The single place `App` state is mutated. Returns follow-up
+    /// messages for the next fold pass (state changes) or the command
+    /// runner (side effects like `PlaySqueak`) to handle.
+    pub fn update(&mut self, msg: Message) -> Vec<Message> {
+        match msg {
+            Message::Pet => {
+                self.happiness = (self.happiness + 0.08).clamp(0.0, 1.0);
+                self.last_pet = Instant::now();
+                self.squeaks += 1;
+                let cue = if self.happiness > 0.66 { Cue::Happy } else { Cue::Neutral };
+                vec![Message::PlaySqueak(cue)]
+            }
+            Message::SwitchSkin => {
+                self.style = match self.style {
+                    RatStyle::Classic => RatStyle::LongTail,
+                    RatStyle::LongTail => RatStyle::Chubby,
+                    RatStyle::Chubby => RatStyle::Classic,
+                };
+                vec![]
+            }
+            Message::Tick(dt) => {
+                self.vibe = (self.vibe + dt * 0.9) % 1.0;
+                self.happiness = (self.happiness - dt * 0.015).clamp(0.0, 1.0);
+                vec![]
+            }
+            Message::Quit => {
+                self.should_quit = true;
+                vec![]
+            }
+            Message::ToggleTheme => {
+                self.theme = self.theme.toggle();
+                vec![]
+            }
+            // Side-effecting; handled by the command runner, not here.
+            Message::PlaySqueak(_) => vec![],
+        }
+    }
+}
+
+/// Whether `(mouse_x, mouse_y)` falls inside the rat's last-rendered area.
+pub fn in_rat_bounds(rat_area: Rect, mouse_x: u16, mouse_y: u16) -> bool {
+    rat_area.contains(ratatui::layout::Position { x: mouse_x, y: mouse_y })
+}
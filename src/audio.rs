@@ -0,0 +1,151 @@
+//! The command runner: executes side-effecting messages that `App::update`
+//! can't perform itself (since `update` only touches state), plus the
+//! optional sound-pack this builds on top of the procedural squeak.
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use rodio::{buffer::SamplesBuffer, source::Source, Sink};
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+use crate::message::{Cue, Message};
+
+const SUPPORTED_EXTENSIONS: [&str; 3] = ["mp3", "aac", "wav"];
+
+/// Sound files discovered in the `sounds/` directory at startup, keyed by
+/// named cue. A cue with no matching file falls back to the procedural
+/// generator.
+pub struct SoundPack {
+    happy: Option<PathBuf>,
+    neutral: Option<PathBuf>,
+}
+
+impl SoundPack {
+    /// Scan `dir` for `squeak_happy.*` / `squeak_neutral.*` files in any
+    /// of [`SUPPORTED_EXTENSIONS`]. A missing or unreadable directory
+    /// just means every cue falls back to synthesis.
+    pub fn load(dir: impl AsRef<Path>) -> Self {
+        let dir = dir.as_ref();
+        Self {
+            happy: find_cue_file(dir, "squeak_happy"),
+            neutral: find_cue_file(dir, "squeak_neutral"),
+        }
+    }
+
+    fn path_for(&self, cue: Cue) -> Option<&Path> {
+        match cue {
+            Cue::Happy => self.happy.as_deref(),
+            Cue::Neutral => self.neutral.as_deref(),
+        }
+    }
+}
+
+fn find_cue_file(dir: &Path, stem: &str) -> Option<PathBuf> {
+    std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.file_stem().and_then(|s| s.to_str()) == Some(stem)
+                && path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .map(|ext| SUPPORTED_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+                    .unwrap_or(false)
+        })
+}
+
+/// Generate a short squeak sound
+fn play_squeak(sink: &Sink, duration_ms: u64) {
+    let total = Duration::from_millis(duration_ms);
+    let steps = 6;
+    let base_freq = 1600.0;
+    let freq_step = 450.0;
+    let seg = total / steps as u32;
+
+    for i in 0..steps {
+        let f = base_freq + i as f32 * freq_step;
+        let vol = 0.25 + 0.12 * (i as f32);
+        let wave = rodio::source::SineWave::new(f)
+            .take_duration(seg)
+            .amplify(vol)
+            .fade_in(Duration::from_millis(8));
+        sink.append(wave);
+    }
+}
+
+/// Decode `path` with symphonia into an in-memory PCM buffer `rodio` can
+/// play. Returns `None` on anything from a missing file to a corrupt
+/// stream — every failure mode degrades to the procedural squeak rather
+/// than propagating an error.
+fn decode_file(path: &Path) -> Option<SamplesBuffer<f32>> {
+    let file = File::open(path).ok()?;
+    let mss = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(&hint, mss, &FormatOptions::default(), &MetadataOptions::default())
+        .ok()?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != CODEC_TYPE_NULL)?;
+    let track_id = track.id;
+    let sample_rate = track.codec_params.sample_rate?;
+    let channels = track.codec_params.channels?.count() as u16;
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .ok()?;
+
+    let mut samples = Vec::new();
+    while let Ok(packet) = format.next_packet() {
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let Ok(decoded) = decoder.decode(&packet) else {
+            continue;
+        };
+        let mut buf = SampleBuffer::<f32>::new(decoded.capacity() as u64, *decoded.spec());
+        buf.copy_interleaved_ref(decoded);
+        samples.extend_from_slice(buf.samples());
+    }
+
+    (!samples.is_empty()).then(|| SamplesBuffer::new(channels, sample_rate, samples))
+}
+
+/// Run the side effects implied by `messages` (currently just
+/// `PlaySqueak`), decoding the sound-pack file for the cue (falling back
+/// to synthesis) on the spawned audio thread so the UI never blocks.
+pub fn run_commands(messages: &[Message], sink: &Arc<Mutex<Sink>>, pack: &Arc<SoundPack>) {
+    for msg in messages {
+        let Message::PlaySqueak(cue) = msg else {
+            continue;
+        };
+        let cue = *cue;
+        let sink = Arc::clone(sink);
+        let pack = Arc::clone(pack);
+        thread::spawn(move || {
+            if let Ok(s) = sink.lock() {
+                match pack.path_for(cue).and_then(decode_file) {
+                    Some(buffer) => s.append(buffer),
+                    None => play_squeak(&s, 140),
+                }
+                s.sleep_until_end();
+            }
+        });
+    }
+}
@@ -0,0 +1,117 @@
+//! Crossterm backend: the default, cross-platform backend.
+
+use std::io;
+use std::time::Duration;
+
+use crossterm::{
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+        MouseButton, MouseEventKind,
+    },
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{backend::CrosstermBackend, Terminal};
+
+use crate::config::{Action, Keymap};
+use crate::key;
+
+use super::Input;
+
+/// A `Terminal` wired up to crossterm over stdout.
+pub type DefaultTerminal = Terminal<CrosstermBackend<io::Stdout>>;
+
+/// Put the terminal into raw mode on the alternate screen and install a
+/// panic hook that restores it first, so a panic's backtrace prints on a
+/// normal, non-mangled screen instead of leaving the user's shell wedged
+/// in raw mode.
+pub fn init() -> io::Result<DefaultTerminal> {
+    let original_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        let _ = restore();
+        original_hook(panic_info);
+    }));
+
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    Terminal::new(CrosstermBackend::new(stdout))
+}
+
+/// Undo everything `init()` did to the terminal. Safe to call more than
+/// once (e.g. once from the panic hook, once from the normal exit path).
+pub fn restore() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        crossterm::cursor::Show,
+    )?;
+    Ok(())
+}
+
+/// Translate a crossterm key into the same `(key::KeyCode,
+/// key::KeyModifiers)` shape [`Keymap`] is keyed by, so keybind remapping
+/// works identically across backends.
+fn translate_key(code: KeyCode, modifiers: KeyModifiers) -> Option<(key::KeyCode, key::KeyModifiers)> {
+    let code = match code {
+        KeyCode::Char(c) => key::KeyCode::Char(c),
+        KeyCode::Enter => key::KeyCode::Enter,
+        KeyCode::Esc => key::KeyCode::Esc,
+        KeyCode::Tab => key::KeyCode::Tab,
+        KeyCode::Backspace => key::KeyCode::Backspace,
+        _ => return None,
+    };
+
+    let mut mods = key::KeyModifiers::NONE;
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        mods |= key::KeyModifiers::CONTROL;
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        mods |= key::KeyModifiers::ALT;
+    }
+    if modifiers.contains(KeyModifiers::SHIFT) {
+        mods |= key::KeyModifiers::SHIFT;
+    }
+
+    Some((code, mods))
+}
+
+fn action_to_input(action: Action) -> Input {
+    match action {
+        Action::Pet => Input::Pet,
+        Action::SwitchSkin => Input::SwitchSkin,
+        Action::Quit => Input::Quit,
+        Action::ToggleTheme => Input::ToggleTheme,
+    }
+}
+
+/// Poll for a crossterm event for up to `timeout` and translate it into
+/// our normalized [`Input`], if it's one we care about. Key presses are
+/// looked up in `keymap` instead of being matched on hard-coded chars,
+/// so remapped controls take effect here. `_terminal` is unused;
+/// crossterm polls stdin directly, independent of the `Terminal` handle.
+pub fn next_input(
+    _terminal: &mut DefaultTerminal,
+    keymap: &Keymap,
+    timeout: Duration,
+) -> io::Result<Option<Input>> {
+    if !event::poll(timeout)? {
+        return Ok(None);
+    }
+
+    let input = match event::read()? {
+        Event::Key(key) if key.kind == KeyEventKind::Press => translate_key(key.code, key.modifiers)
+            .and_then(|(code, mods)| keymap.action_for(code, mods))
+            .map(action_to_input),
+        Event::Mouse(m) => match m.kind {
+            MouseEventKind::Down(MouseButton::Left) => Some(Input::MouseClick { x: m.column, y: m.row }),
+            _ => None,
+        },
+        Event::Resize(_, _) => Some(Input::Resize),
+        _ => None,
+    };
+
+    Ok(input)
+}
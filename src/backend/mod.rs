@@ -0,0 +1,51 @@
+//! Terminal backend selection.
+//!
+//! `terminal-rat` talks to three different TUI backends (crossterm,
+//! termion, termwiz) behind matching cargo features, exactly one of
+//! which is enabled in a given build (crossterm by default). Each
+//! backend module exposes the same three functions — `init`, `restore`,
+//! and `next_input` — plus the [`DefaultTerminal`] type alias, so
+//! `main()` never names a concrete backend type or native event type.
+
+#[cfg(not(any(feature = "crossterm", feature = "termion", feature = "termwiz")))]
+compile_error!(
+    "terminal-rat needs exactly one backend feature enabled (crossterm, termion, or termwiz); \
+     none were. Build with `--features crossterm` (the default) or pick one of the others."
+);
+
+#[cfg(any(
+    all(feature = "crossterm", feature = "termion"),
+    all(feature = "crossterm", feature = "termwiz"),
+    all(feature = "termion", feature = "termwiz"),
+))]
+compile_error!(
+    "terminal-rat needs exactly one backend feature enabled (crossterm, termion, or termwiz); \
+     more than one were. Build with `--no-default-features --features <one of them>`."
+);
+
+#[cfg(feature = "crossterm")]
+mod crossterm_backend;
+#[cfg(feature = "termion")]
+mod termion_backend;
+#[cfg(feature = "termwiz")]
+mod termwiz_backend;
+
+#[cfg(feature = "crossterm")]
+pub use crossterm_backend::{init, next_input, restore, DefaultTerminal};
+#[cfg(feature = "termion")]
+pub use termion_backend::{init, next_input, restore, DefaultTerminal};
+#[cfg(feature = "termwiz")]
+pub use termwiz_backend::{init, next_input, restore, DefaultTerminal};
+
+/// Backend-agnostic input the event loop reacts to. Each backend module
+/// maps its native key/mouse event type onto this before it ever reaches
+/// `main`'s event match.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Input {
+    Pet,
+    SwitchSkin,
+    Quit,
+    MouseClick { x: u16, y: u16 },
+    Resize,
+    ToggleTheme,
+}
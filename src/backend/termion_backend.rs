@@ -0,0 +1,151 @@
+//! Termion backend: Unix-only, no crossterm dependency.
+
+use std::io::{self, Stdout, Write};
+use std::mem::MaybeUninit;
+use std::os::fd::AsRawFd;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use std::time::Duration;
+
+use ratatui::{backend::TermionBackend, Terminal};
+use termion::event::{Event as TEvent, Key, MouseButton, MouseEvent};
+use termion::input::{MouseTerminal, TermRead};
+use termion::raw::{IntoRawMode, RawTerminal};
+use termion::screen::{AlternateScreen, IntoAlternateScreen};
+
+use crate::config::{Action, Keymap};
+use crate::key::{KeyCode, KeyModifiers};
+
+use super::Input;
+
+type TermionStdout = AlternateScreen<MouseTerminal<RawTerminal<Stdout>>>;
+
+/// A `Terminal` wired up to termion's raw mode + alternate screen + mouse
+/// capture stack.
+pub type DefaultTerminal = Terminal<TermionBackend<TermionStdout>>;
+
+/// The termios state stdout was in before `init()` put it in raw mode,
+/// saved so `restore()` can put it back synchronously instead of relying
+/// on `RawTerminal`'s `Drop` impl (see `restore()` below).
+static ORIGINAL_TERMIOS: OnceLock<libc::termios> = OnceLock::new();
+
+/// CSI sequence undoing `MouseTerminal`'s `ENTER_MOUSE_SEQUENCE` and
+/// `AlternateScreen`'s switch to the alternate buffer, in reverse order.
+/// termion doesn't expose either sequence (or its internal `Termios`
+/// type) outside the crate, so this mirrors them by hand.
+const EXIT_SEQUENCE: &str = "\x1b[?1006l\x1b[?1015l\x1b[?1002l\x1b[?1000l\x1b[?1049l";
+
+/// Put the terminal into raw mode on the alternate screen with mouse
+/// capture enabled, and install a panic hook that restores it first.
+pub fn init() -> io::Result<DefaultTerminal> {
+    let original_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        let _ = restore();
+        original_hook(panic_info);
+    }));
+
+    let mut termios = MaybeUninit::uninit();
+    if unsafe { libc::tcgetattr(io::stdout().as_raw_fd(), termios.as_mut_ptr()) } == 0 {
+        let _ = ORIGINAL_TERMIOS.set(unsafe { termios.assume_init() });
+    }
+
+    let screen = io::stdout().into_raw_mode()?;
+    let screen = MouseTerminal::from(screen);
+    let screen = screen.into_alternate_screen()?;
+    Terminal::new(TermionBackend::new(screen))
+}
+
+/// Undo everything `init()` did to the terminal, synchronously. The
+/// `RawTerminal`/`MouseTerminal`/`AlternateScreen` guards wrapping stdout
+/// would do this on `Drop`, but that doesn't happen until the `Terminal`
+/// unwinds — which, for a panic, is *after* the panic hook that calls
+/// `restore()` has already printed its backtrace. So this duplicates
+/// their teardown explicitly instead of waiting for it: restore the
+/// termios state saved in `init()`, then write the same CSI sequences
+/// the guards would write on drop (writing them twice, once here and
+/// once when the guards do eventually drop, is harmless).
+pub fn restore() -> io::Result<()> {
+    if let Some(termios) = ORIGINAL_TERMIOS.get() {
+        unsafe {
+            libc::tcsetattr(io::stdout().as_raw_fd(), libc::TCSANOW, termios);
+        }
+    }
+
+    write!(io::stdout(), "{EXIT_SEQUENCE}{}", termion::cursor::Show)?;
+    io::stdout().flush()
+}
+
+/// termion has no poll-with-timeout of its own, so a single background
+/// thread owns the blocking `stdin().events()` iterator for the whole
+/// process and forwards what it reads over a channel. `next_input` then
+/// just does a bounded `recv_timeout` on that channel — the only thing
+/// ever blocking on stdin is this one thread, started lazily on first use.
+fn event_receiver() -> &'static Mutex<Receiver<io::Result<TEvent>>> {
+    static RECEIVER: OnceLock<Mutex<Receiver<io::Result<TEvent>>>> = OnceLock::new();
+    RECEIVER.get_or_init(|| {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for event in io::stdin().events() {
+                if tx.send(event).is_err() {
+                    break;
+                }
+            }
+        });
+        Mutex::new(rx)
+    })
+}
+
+/// Translate a termion key into the same `(KeyCode, KeyModifiers)` shape
+/// [`Keymap`] is keyed by, so keybind remapping works identically across
+/// backends.
+fn translate_key(key: Key) -> Option<(KeyCode, KeyModifiers)> {
+    match key {
+        Key::Char('\n') => Some((KeyCode::Enter, KeyModifiers::NONE)),
+        Key::Char('\t') => Some((KeyCode::Tab, KeyModifiers::NONE)),
+        Key::Char(c) => Some((KeyCode::Char(c), KeyModifiers::NONE)),
+        Key::Ctrl(c) => Some((KeyCode::Char(c), KeyModifiers::CONTROL)),
+        Key::Alt(c) => Some((KeyCode::Char(c), KeyModifiers::ALT)),
+        Key::Esc => Some((KeyCode::Esc, KeyModifiers::NONE)),
+        Key::Backspace => Some((KeyCode::Backspace, KeyModifiers::NONE)),
+        _ => None,
+    }
+}
+
+fn action_to_input(action: Action) -> Input {
+    match action {
+        Action::Pet => Input::Pet,
+        Action::SwitchSkin => Input::SwitchSkin,
+        Action::Quit => Input::Quit,
+        Action::ToggleTheme => Input::ToggleTheme,
+    }
+}
+
+/// Wait up to `timeout` for the next termion event and translate it into
+/// our normalized [`Input`], if it's one we care about. Key presses are
+/// looked up in `keymap` the same way `crossterm_backend` does.
+/// `_terminal` is unused; termion reads stdin via its own background
+/// thread, independent of the `Terminal` handle.
+pub fn next_input(
+    _terminal: &mut DefaultTerminal,
+    keymap: &Keymap,
+    timeout: Duration,
+) -> io::Result<Option<Input>> {
+    let event = match event_receiver().lock().unwrap().recv_timeout(timeout) {
+        Ok(event) => event?,
+        Err(RecvTimeoutError::Timeout) => return Ok(None),
+        Err(RecvTimeoutError::Disconnected) => return Ok(None),
+    };
+
+    let input = match event {
+        TEvent::Key(key) => translate_key(key)
+            .and_then(|(code, mods)| keymap.action_for(code, mods))
+            .map(action_to_input),
+        TEvent::Mouse(MouseEvent::Press(MouseButton::Left, x, y)) => {
+            Some(Input::MouseClick { x: x.saturating_sub(1), y: y.saturating_sub(1) })
+        }
+        _ => None,
+    };
+
+    Ok(input)
+}
@@ -0,0 +1,138 @@
+//! Termwiz backend: used on platforms where termion/crossterm are less
+//! viable (e.g. via wezterm's terminal layer).
+
+use std::io::{self, Write};
+use std::mem::MaybeUninit;
+use std::os::fd::AsRawFd;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use ratatui::{backend::TermwizBackend, Terminal};
+use termwiz::input::{InputEvent, KeyCode as TKeyCode, Modifiers, MouseButtons};
+use termwiz::terminal::Terminal as TermwizTerminalTrait;
+
+use crate::config::{Action, Keymap};
+use crate::key::{KeyCode, KeyModifiers};
+
+use super::Input;
+
+/// A `Terminal` wired up to termwiz's buffered terminal.
+pub type DefaultTerminal = Terminal<TermwizBackend>;
+
+/// The termios state stdout was in before `init()` put it in raw mode,
+/// saved so `restore()` can put it back synchronously instead of relying
+/// on the termwiz `Terminal`'s `Drop` impl (see `restore()` below).
+static ORIGINAL_TERMIOS: OnceLock<libc::termios> = OnceLock::new();
+
+/// CSI sequence undoing what `TermwizBackend::new` turns on — bracketed
+/// paste, SGR and any-event mouse tracking, and the alternate screen —
+/// in reverse order. Mirrors `UnixTerminal::set_raw_mode`'s DEC private
+/// modes by hand, since restoring them needs the concrete `Terminal`
+/// handle `init()` hasn't created yet when the panic hook is installed.
+const EXIT_SEQUENCE: &str = "\x1b[?2004l\x1b[?1006l\x1b[?1003l\x1b[?1049l";
+
+/// Put the terminal into raw mode on the alternate screen, and install a
+/// panic hook that restores it first.
+pub fn init() -> io::Result<DefaultTerminal> {
+    let original_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        let _ = restore();
+        original_hook(panic_info);
+    }));
+
+    let mut termios = MaybeUninit::uninit();
+    if unsafe { libc::tcgetattr(io::stdout().as_raw_fd(), termios.as_mut_ptr()) } == 0 {
+        let _ = ORIGINAL_TERMIOS.set(unsafe { termios.assume_init() });
+    }
+
+    let backend = TermwizBackend::new().map_err(|e| io::Error::other(e.to_string()))?;
+    Terminal::new(backend)
+}
+
+/// Undo everything `init()` did to the terminal, synchronously. termwiz's
+/// own `Terminal` impl would do this on `Drop`, but that doesn't happen
+/// until our `Terminal` unwinds — which, for a panic, is *after* the
+/// panic hook that calls `restore()` has already printed its backtrace.
+/// So this duplicates that teardown explicitly instead of waiting for
+/// it: restore the termios state saved in `init()`, then write the same
+/// CSI sequences termwiz's own teardown would (writing them twice, once
+/// here and once when the `Terminal` does eventually drop, is harmless).
+pub fn restore() -> io::Result<()> {
+    if let Some(termios) = ORIGINAL_TERMIOS.get() {
+        unsafe {
+            libc::tcsetattr(io::stdout().as_raw_fd(), libc::TCSANOW, termios);
+        }
+    }
+
+    write!(io::stdout(), "{EXIT_SEQUENCE}")?;
+    io::stdout().flush()
+}
+
+/// Translate a termwiz key + modifiers into the same `(KeyCode,
+/// KeyModifiers)` shape [`Keymap`] is keyed by, so keybind remapping
+/// works identically across backends.
+fn translate_key(key: TKeyCode, modifiers: Modifiers) -> Option<(KeyCode, KeyModifiers)> {
+    let code = match key {
+        TKeyCode::Char(c) => KeyCode::Char(c),
+        TKeyCode::Enter => KeyCode::Enter,
+        TKeyCode::Escape => KeyCode::Esc,
+        TKeyCode::Tab => KeyCode::Tab,
+        TKeyCode::Backspace => KeyCode::Backspace,
+        _ => return None,
+    };
+
+    let mut mods = KeyModifiers::NONE;
+    if modifiers.contains(Modifiers::CTRL) {
+        mods |= KeyModifiers::CONTROL;
+    }
+    if modifiers.contains(Modifiers::ALT) {
+        mods |= KeyModifiers::ALT;
+    }
+    if modifiers.contains(Modifiers::SHIFT) {
+        mods |= KeyModifiers::SHIFT;
+    }
+
+    Some((code, mods))
+}
+
+fn action_to_input(action: Action) -> Input {
+    match action {
+        Action::Pet => Input::Pet,
+        Action::SwitchSkin => Input::SwitchSkin,
+        Action::Quit => Input::Quit,
+        Action::ToggleTheme => Input::ToggleTheme,
+    }
+}
+
+/// Poll the termwiz backend for up to `timeout` and translate the next
+/// input event into our normalized [`Input`], if it's one we care about.
+/// Key presses are looked up in `keymap` the same way `crossterm_backend`
+/// does.
+pub fn next_input(
+    terminal: &mut DefaultTerminal,
+    keymap: &Keymap,
+    timeout: Duration,
+) -> io::Result<Option<Input>> {
+    let Some(event) = terminal
+        .backend_mut()
+        .buffered_terminal_mut()
+        .terminal()
+        .poll_input(Some(timeout))
+        .map_err(io::Error::other)?
+    else {
+        return Ok(None);
+    };
+
+    let input = match event {
+        InputEvent::Key(key) => translate_key(key.key, key.modifiers)
+            .and_then(|(code, mods)| keymap.action_for(code, mods))
+            .map(action_to_input),
+        InputEvent::Mouse(m) if m.mouse_buttons.contains(MouseButtons::LEFT) => {
+            Some(Input::MouseClick { x: m.x, y: m.y })
+        }
+        InputEvent::Resized { .. } => Some(Input::Resize),
+        _ => None,
+    };
+
+    Ok(input)
+}
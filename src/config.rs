@@ -0,0 +1,124 @@
+//! User-configurable keybindings, loaded from a `config.ron` in the
+//! platform config directory (e.g. `~/.config/terminal-rat/config.ron` on
+//! Linux). Falls back to sensible defaults when the file is missing or
+//! fails to parse, so the rat always starts even with a broken config.
+
+use std::collections::HashMap;
+use std::fs;
+
+use serde::Deserialize;
+
+use crate::key::{KeyCode, KeyModifiers};
+use crate::theme::Mode;
+
+/// Something a keypress can trigger. Kept separate from [`crate::backend::Input`]
+/// so the config format doesn't have to change shape every time the event
+/// loop grows a new kind of input (mouse clicks, resizes, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Action {
+    Pet,
+    SwitchSkin,
+    Quit,
+    ToggleTheme,
+}
+
+/// On-disk shape of `config.ron`: a map from key-string (`"<p>"`,
+/// `"<Ctrl-c>"`, `"<esc>"`) to the [`Action`] it triggers, plus an
+/// optional manual override of the auto-detected light/dark theme.
+#[derive(Debug, Deserialize)]
+pub struct Config {
+    pub keybinds: HashMap<String, Action>,
+    #[serde(default)]
+    pub theme: Option<Mode>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let mut keybinds = HashMap::new();
+        keybinds.insert("<p>".to_string(), Action::Pet);
+        keybinds.insert("<enter>".to_string(), Action::Pet);
+        keybinds.insert("<space>".to_string(), Action::Pet);
+        keybinds.insert("<s>".to_string(), Action::SwitchSkin);
+        keybinds.insert("<q>".to_string(), Action::Quit);
+        keybinds.insert("<esc>".to_string(), Action::Quit);
+        keybinds.insert("<t>".to_string(), Action::ToggleTheme);
+        Self { keybinds, theme: None }
+    }
+}
+
+/// A parsed, ready-to-consult lookup table from `(KeyCode, KeyModifiers)`
+/// to the [`Action`] it should trigger, plus whatever else `config.ron`
+/// configured (currently just a theme override).
+pub struct Keymap {
+    bindings: HashMap<(KeyCode, KeyModifiers), Action>,
+    theme_override: Option<Mode>,
+}
+
+impl Keymap {
+    /// Build a keymap from a [`Config`], silently dropping any
+    /// key-string we fail to parse rather than failing the whole load.
+    fn from_config(config: Config) -> Self {
+        let mut bindings = HashMap::new();
+        for (key_string, action) in config.keybinds {
+            if let Some(key) = parse_key(&key_string) {
+                bindings.insert(key, action);
+            }
+        }
+        Self { bindings, theme_override: config.theme }
+    }
+
+    pub fn action_for(&self, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        self.bindings.get(&(code, modifiers)).copied()
+    }
+
+    /// The manually configured theme, if `config.ron` set one. `None`
+    /// means the theme should be auto-detected from the terminal.
+    pub fn theme_override(&self) -> Option<Mode> {
+        self.theme_override
+    }
+}
+
+/// Load `config.ron` from the platform config dir, falling back to
+/// [`Config::default`] if it's absent or fails to parse.
+pub fn load_keymap() -> Keymap {
+    let config = read_config_file().unwrap_or_default();
+    Keymap::from_config(config)
+}
+
+fn read_config_file() -> Option<Config> {
+    let dirs = directories::ProjectDirs::from("", "", "terminal-rat")?;
+    let path = dirs.config_dir().join("config.ron");
+    let contents = fs::read_to_string(path).ok()?;
+    ron::from_str(&contents).ok()
+}
+
+/// Parse a key-string like `"<p>"`, `"<Ctrl-c>"`, or `"<esc>"` into a
+/// `(KeyCode, KeyModifiers)` pair. Expects the `<...>` bracket form;
+/// modifiers are hyphen-separated prefixes (`Ctrl-`, `Alt-`, `Shift-`).
+fn parse_key(key_string: &str) -> Option<(KeyCode, KeyModifiers)> {
+    let inner = key_string.strip_prefix('<')?.strip_suffix('>')?;
+    let mut parts = inner.split('-').collect::<Vec<_>>();
+    let key_name = parts.pop()?;
+
+    let mut modifiers = KeyModifiers::NONE;
+    for part in parts {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" => modifiers |= KeyModifiers::CONTROL,
+            "alt" => modifiers |= KeyModifiers::ALT,
+            "shift" => modifiers |= KeyModifiers::SHIFT,
+            _ => return None,
+        }
+    }
+
+    let code = match key_name.to_ascii_lowercase().as_str() {
+        "esc" => KeyCode::Esc,
+        "enter" => KeyCode::Enter,
+        "space" => KeyCode::Char(' '),
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        other if other.chars().count() == 1 => KeyCode::Char(other.chars().next()?),
+        _ => return None,
+    };
+
+    Some((code, modifiers))
+}
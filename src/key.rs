@@ -0,0 +1,46 @@
+//! Backend-agnostic key representation. [`Keymap`](crate::config::Keymap)
+//! is keyed by these types rather than any one backend's native key
+//! event, so `config.rs` (and the key-parsing it does) doesn't pull in
+//! crossterm/termion/termwiz just to describe a keybinding — each
+//! backend module is responsible for translating its own key events into
+//! this shape before consulting the keymap.
+
+use std::ops::{BitOr, BitOrAssign};
+
+/// The handful of keys `terminal-rat` cares about. Not meant to be a
+/// complete terminal key model — just enough to cover the actions in
+/// [`crate::config::Action`] and the keys users bind to them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyCode {
+    Char(char),
+    Enter,
+    Esc,
+    Tab,
+    Backspace,
+}
+
+/// A bitset of held modifier keys, mirroring the small subset of
+/// crossterm's `KeyModifiers` that key-string parsing understands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyModifiers(u8);
+
+impl KeyModifiers {
+    pub const NONE: Self = Self(0);
+    pub const CONTROL: Self = Self(0b001);
+    pub const ALT: Self = Self(0b010);
+    pub const SHIFT: Self = Self(0b100);
+}
+
+impl BitOr for KeyModifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for KeyModifiers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
@@ -0,0 +1,26 @@
+//! Messages that flow through the update loop.
+//!
+//! Input and the tick timer are translated into `Message`s, which are
+//! folded through [`crate::app::App::update`] one at a time. `update` can
+//! itself emit follow-up messages, which get folded in turn before the
+//! next frame is rendered; side-effecting ones (e.g. the `PlaySqueak`
+//! that `Pet` emits) go to [`crate::audio::run_commands`] instead.
+
+#[derive(Debug, Clone, Copy)]
+pub enum Message {
+    Pet,
+    SwitchSkin,
+    Tick(f32),
+    Quit,
+    PlaySqueak(Cue),
+    ToggleTheme,
+}
+
+/// Which named squeak cue to play, chosen by the current happiness
+/// bucket. Each cue maps to its own sound-pack file, falling back to the
+/// procedural generator when no file is found.
+#[derive(Debug, Clone, Copy)]
+pub enum Cue {
+    Happy,
+    Neutral,
+}
@@ -0,0 +1,150 @@
+//! Color theme, auto-detected from the terminal's background at startup
+//! (with a manual override via config/keybind) so `terminal-rat` stays
+//! legible on both light and dark terminals.
+
+use std::io::Write;
+use std::time::Duration;
+
+use ratatui::style::Color;
+use serde::Deserialize;
+
+/// Which palette is active. Background detection (or the config
+/// override) picks one of these two; there is no separate "auto" mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Mode {
+    Dark,
+    Light,
+}
+
+/// The colors used across `draw_ui`, grouped by where they're used
+/// rather than by raw value, so a palette swap never requires touching
+/// `ui.rs`.
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub mode: Mode,
+    pub accent: Color,
+    pub squeak_count: Color,
+    pub happiness_gauge: Color,
+    pub bar_value_fg: Color,
+    pub bar_value_bg: Color,
+    pub footer_accent: Color,
+}
+
+impl Theme {
+    pub fn for_mode(mode: Mode) -> Self {
+        match mode {
+            Mode::Dark => Self::dark(),
+            Mode::Light => Self::light(),
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        Self::for_mode(match self.mode {
+            Mode::Dark => Mode::Light,
+            Mode::Light => Mode::Dark,
+        })
+    }
+
+    fn dark() -> Self {
+        Self {
+            mode: Mode::Dark,
+            accent: Color::Magenta,
+            squeak_count: Color::Yellow,
+            happiness_gauge: Color::Green,
+            bar_value_fg: Color::Black,
+            bar_value_bg: Color::White,
+            footer_accent: Color::LightMagenta,
+        }
+    }
+
+    fn light() -> Self {
+        Self {
+            mode: Mode::Light,
+            accent: Color::Blue,
+            squeak_count: Color::Rgb(160, 90, 0),
+            happiness_gauge: Color::Rgb(0, 120, 60),
+            bar_value_fg: Color::White,
+            bar_value_bg: Color::Black,
+            footer_accent: Color::Blue,
+        }
+    }
+}
+
+/// Ask the terminal for its background color via an OSC 11 query and
+/// classify it as dark or light. Returns `None` if the terminal doesn't
+/// answer within a short timeout (or the reply can't be parsed) — the
+/// caller should fall back to a default mode in that case.
+///
+/// Reads the reply directly, in this thread, with stdin switched to
+/// non-blocking for the duration of the read loop: no reader thread is
+/// spawned, so nothing is left racing the backend's own input source for
+/// stdin bytes once this function returns.
+#[cfg(unix)]
+pub fn detect_background_mode() -> Option<Mode> {
+    use std::io::Read;
+    use std::os::fd::AsRawFd;
+    use std::time::Instant;
+
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]11;?\x07").ok()?;
+    stdout.flush().ok()?;
+
+    let stdin = std::io::stdin();
+    let fd = stdin.as_raw_fd();
+    let original_flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+    if original_flags < 0 {
+        return None;
+    }
+    unsafe {
+        libc::fcntl(fd, libc::F_SETFL, original_flags | libc::O_NONBLOCK);
+    }
+
+    let mut lock = stdin.lock();
+    let mut response = Vec::new();
+    let mut byte = [0u8; 1];
+    let deadline = Instant::now() + Duration::from_millis(200);
+    while Instant::now() < deadline && response.len() < 64 {
+        match lock.read(&mut byte) {
+            Ok(1) => {
+                response.push(byte[0]);
+                if byte[0] == 0x07 || response.ends_with(b"\x1b\\") {
+                    break;
+                }
+            }
+            Ok(_) => break,
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            Err(_) => break,
+        }
+    }
+
+    unsafe {
+        libc::fcntl(fd, libc::F_SETFL, original_flags);
+    }
+
+    parse_osc11_response(&response)
+}
+
+/// Non-Unix platforms don't get OSC 11 background detection; callers
+/// fall back to a default mode.
+#[cfg(not(unix))]
+pub fn detect_background_mode() -> Option<Mode> {
+    None
+}
+
+/// Parse an OSC 11 reply of the form `\x1b]11;rgb:RRRR/GGGG/BBBB\x07` into
+/// a light/dark classification using perceptual luminance.
+fn parse_osc11_response(bytes: &[u8]) -> Option<Mode> {
+    let text = String::from_utf8_lossy(bytes);
+    let rgb = text.split("rgb:").nth(1)?;
+    let mut channels = rgb.split(['/', '\x07', '\x1b']).filter(|s| !s.is_empty());
+
+    let channel = |s: &str| u16::from_str_radix(s, 16).ok().map(|c| c as f32 / 65535.0);
+    let r = channel(channels.next()?)?;
+    let g = channel(channels.next()?)?;
+    let b = channel(channels.next()?)?;
+
+    let luminance = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    Some(if luminance < 0.5 { Mode::Dark } else { Mode::Light })
+}
@@ -0,0 +1,150 @@
+//! Rendering. `draw_ui` is a pure function of `&App`: it never mutates
+//! app state, it only reads it and returns the area the rat was drawn
+//! in, which the event loop keeps around for mouse hit-testing.
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{BarChart, Block, Borders, Gauge, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::{App, RatStyle};
+
+/// Pad ASCII block lines to equal width
+fn pad_block(lines: Vec<String>) -> Vec<String> {
+    let width = lines.iter().map(|s| s.chars().count()).max().unwrap_or(0);
+    lines
+        .into_iter()
+        .map(|s| {
+            let pad = width.saturating_sub(s.chars().count());
+            format!("{s}{}", " ".repeat(pad))
+        })
+        .collect()
+}
+
+/// Return ASCII art for a given rat style
+fn rat_art(vibe: f32, happy: f32, style: RatStyle) -> Vec<String> {
+    match style {
+        RatStyle::Classic => {
+            let tail = if vibe < 0.5 { "~" } else { "≈" };
+            let eye = if happy > 0.66 { "•" } else { "." };
+            let blush = if happy > 0.66 { "˘" } else { " " };
+            pad_block(vec![
+                format!("  (\\_/)     {tail}{tail}{tail}"),
+                format!("  ({eye}{blush}{eye})     "),
+                "  (   )    ".to_string(),
+                "  (   )    ".to_string(),
+                "   \" \"     ".to_string(),
+            ])
+        }
+        RatStyle::LongTail => {
+            let tail = if vibe < 0.5 { "~~" } else { "≈≈" };
+            let eye = if happy > 0.5 { "•" } else { "." };
+            pad_block(vec![
+                format!("  (\\_/)      {tail}{tail}{tail}{tail}"),
+                format!("  ({eye} .)    "),
+                "  (   )    ".to_string(),
+                "   v v     ".to_string(),
+            ])
+        }
+        RatStyle::Chubby => {
+            let tail = if vibe < 0.5 { "~" } else { "≈" };
+            let eye = if happy > 0.7 { "•" } else { "o" };
+            pad_block(vec![
+                format!("  (\\_/)    {tail}{tail}{tail}"),
+                format!(" ( {eye} {eye} ) "),
+                " (  -  ) ".to_string(),
+                " (     ) ".to_string(),
+                "  \"   \"  ".to_string(),
+            ])
+        }
+    }
+}
+
+pub fn draw_ui(frame: &mut Frame, app: &App) -> Rect {
+    let size = frame.area();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(6), Constraint::Length(3)])
+        .split(size);
+
+    let theme = app.theme;
+
+    let header = Paragraph::new(Line::from(vec![
+        Span::styled("terminal-rat ", Style::default().fg(theme.accent)),
+        Span::raw("— click or press 'p' to pet, 's' to switch skins, 't' to toggle theme, 'q' to quit."),
+    ]))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).title("Squeak Guide"));
+    frame.render_widget(header, chunks[0]);
+
+    let center = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(chunks[1]);
+
+    let rat_block = Block::default().borders(Borders::ALL).title("Your Rat");
+
+    let art = rat_art(app.vibe, app.happiness, app.style);
+    let rat_text = art
+        .iter()
+        .map(|l| Line::from(Span::raw(l.clone())))
+        .collect::<Vec<_>>();
+
+    let rat_para = Paragraph::new(rat_text)
+        .alignment(Alignment::Center) // now works with padding
+        .wrap(Wrap { trim: false })
+        .block(rat_block);
+
+    let rat_area = center[0];
+    frame.render_widget(rat_para, rat_area);
+
+    let happiness_gauge = Gauge::default()
+        .block(Block::default().borders(Borders::ALL).title("Happiness"))
+        .gauge_style(Style::default().fg(theme.happiness_gauge))
+        .ratio(app.happiness as f64)
+        .label(format!("{:.0}%", app.happiness * 100.0));
+
+    let bars: Vec<(&str, u64)> = (0..8)
+        .map(|i| {
+            let v = ((app.vibe * 8.0) as i32 - i).unsigned_abs() as u64 % 4 + 1;
+            (" ", v)
+        })
+        .collect();
+    let barchart = BarChart::default()
+        .block(Block::default().borders(Borders::ALL).title("Energy"))
+        .data(&bars)
+        .bar_width(2)
+        .bar_gap(1)
+        .value_style(Style::default().fg(theme.bar_value_fg).bg(theme.bar_value_bg));
+
+    let right = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Length(7), Constraint::Min(3)])
+        .split(center[1]);
+
+    let squeak_info = Paragraph::new(Line::from(vec![
+        Span::raw("Squeaks so far: "),
+        Span::styled(format!("{}", app.squeaks), Style::default().fg(theme.squeak_count)),
+        Span::raw("   (pet to squeak)"),
+    ]))
+        .block(Block::default().borders(Borders::ALL).title("Stats"));
+
+    frame.render_widget(happiness_gauge, right[0]);
+    frame.render_widget(barchart, right[1]);
+    frame.render_widget(squeak_info, right[2]);
+
+    let footer = Paragraph::new(Line::from(vec![
+        Span::raw("Made with ratatui + crossterm + rodio. "),
+        Span::styled("Squeak!", Style::default().fg(theme.footer_accent)),
+    ]))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).title("About"));
+
+    frame.render_widget(footer, chunks[2]);
+
+    rat_area
+}